@@ -1,6 +1,8 @@
 use super::{take_item_attrs, get_doc_literals};
+use super::docs::DocInfo;
 use quote::ToTokens;
 use syn::spanned::Spanned;
+use std::collections::HashMap;
 
 /// List of additional token to be used for parsing.
 mod keyword {
@@ -9,6 +11,7 @@ mod keyword {
 	syn::custom_keyword!(weight);
 	syn::custom_keyword!(compact);
 	syn::custom_keyword!(pallet);
+	syn::custom_keyword!(call_index);
 }
 
 /// Definition of dispatchables typically `impl<T: Trait> Call for Module<T> { ... }`
@@ -31,14 +34,18 @@ pub struct CallVariantDef {
 	pub args: Vec<(bool, syn::Ident, Box<syn::Type>)>,
 	/// Weight formula.
 	pub weight: syn::Expr,
+	/// Explicit call index via `#[pallet::call_index(n)]`, if given. Methods without it fall
+	/// back to their declaration-order position.
+	pub index: Option<u8>,
 	/// Docs, used for metadata.
-	pub docs: Vec<syn::Lit>,
+	pub docs: DocInfo,
 }
 
 /// Attributes for functions in call impl block.
-/// Parse for `#[pallet::weight = expr]`
-pub struct FunctionAttr {
-	weight: syn::Expr,
+/// Parse for `#[pallet::weight = expr]` or `#[pallet::call_index(n)]`
+pub enum FunctionAttr {
+	Weight(syn::Expr),
+	CallIndex(u8),
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -49,12 +56,24 @@ impl syn::parse::Parse for FunctionAttr {
 		content.parse::<keyword::pallet>()?;
 		content.parse::<syn::Token![::]>()?;
 
-		content.parse::<keyword::weight>()?;
-		content.parse::<syn::Token![=]>()?;
-
-		Ok(FunctionAttr {
-			weight: content.parse::<syn::Expr>()?,
-		})
+		let lookahead = content.lookahead1();
+		if lookahead.peek(keyword::weight) {
+			content.parse::<keyword::weight>()?;
+			content.parse::<syn::Token![=]>()?;
+			Ok(FunctionAttr::Weight(content.parse::<syn::Expr>()?))
+		} else if lookahead.peek(keyword::call_index) {
+			content.parse::<keyword::call_index>()?;
+			let buffer;
+			syn::parenthesized!(buffer in content);
+			let index = buffer.parse::<syn::LitInt>()?;
+			if !index.suffix().is_empty() {
+				let msg = "Invalid pallet::call_index, number literal must not have a suffix";
+				return Err(syn::Error::new(index.span(), msg));
+			}
+			Ok(FunctionAttr::CallIndex(index.base10_parse()?))
+		} else {
+			Err(lookahead.error())
+		}
 	}
 }
 
@@ -95,74 +114,150 @@ impl CallDef {
 			})?.1;
 		let call = syn::parse2::<keyword::Call>(call.to_token_stream())?;
 
+		let mut errors: Vec<syn::Error> = vec![];
 		let mut methods = vec![];
 		for impl_item in &mut item.items {
-			if let syn::ImplItem::Method(method) = impl_item {
-				if method.sig.inputs.len() == 0 {
-					let msg = "Invalid pallet::call, must have at least origin arg";
-					return Err(syn::Error::new(method.sig.inputs.span(), msg));
+			let method = if let syn::ImplItem::Method(method) = impl_item {
+				method
+			} else {
+				let msg = "Invalid pallet::call, only method accepted";
+				errors.push(syn::Error::new(impl_item.span(), msg));
+				continue;
+			};
+
+			if method.sig.inputs.len() == 0 {
+				let msg = "Invalid pallet::call, must have at least origin arg";
+				errors.push(syn::Error::new(method.sig.inputs.span(), msg));
+				continue;
+			}
+			if let Err(e) = super::check_dispatchable_first_arg(&method.sig.inputs[0]) {
+				errors.push(e);
+				continue;
+			}
+
+			if let syn::ReturnType::Type(_, type_) = &method.sig.output {
+				if let Err(e) = syn::parse2::<keyword::DispatchResultWithPostInfo>(
+					type_.to_token_stream(),
+				) {
+					errors.push(e);
+					continue;
 				}
-				super::check_dispatchable_first_arg(&method.sig.inputs[0])?;
+			} else {
+				let msg = "Invalid pallet::call, require return type \
+					DispatchResultWithPostInfo";
+				errors.push(syn::Error::new(method.sig.span(), msg));
+				continue;
+			}
+
+			let call_var_attrs: Vec<FunctionAttr> = match take_item_attrs(&mut method.attrs) {
+				Ok(attrs) => attrs,
+				Err(e) => {
+					errors.push(e);
+					continue;
+				},
+			};
 
-				if let syn::ReturnType::Type(_, type_) = &method.sig.output {
-					syn::parse2::<keyword::DispatchResultWithPostInfo>(type_.to_token_stream())?;
+			let weight_attrs: Vec<_> = call_var_attrs.iter()
+				.filter(|attr| matches!(attr, FunctionAttr::Weight(_)))
+				.collect();
+			if weight_attrs.len() != 1 {
+				let msg = if weight_attrs.is_empty() {
+					"Invalid pallet::call, require weight attribute i.e. `#[pallet::weight]`"
 				} else {
-					let msg = "Invalid pallet::call, require return type \
-						DispatchResultWithPostInfo";
-					return Err(syn::Error::new(method.sig.span(), msg));
-				}
+					"Invalid pallet::call, to many weight attribute given"
+				};
+				errors.push(syn::Error::new(method.sig.span(), msg));
+				continue;
+			}
+
+			let call_index_attrs: Vec<_> = call_var_attrs.iter()
+				.filter(|attr| matches!(attr, FunctionAttr::CallIndex(_)))
+				.collect();
+			if call_index_attrs.len() > 1 {
+				let msg = "Invalid pallet::call, too many call_index attributes given";
+				errors.push(syn::Error::new(method.sig.span(), msg));
+				continue;
+			}
 
-				let mut call_var_attrs: Vec<FunctionAttr> = take_item_attrs(&mut method.attrs)?;
+			let weight = call_var_attrs.iter()
+				.find_map(|attr| match attr {
+					FunctionAttr::Weight(w) => Some(w.clone()),
+					_ => None,
+				})
+				.expect("Exactly one weight attribute checked above; qed");
 
-				if call_var_attrs.len() != 1 {
-					let msg = if call_var_attrs.len() == 0 {
-						"Invalid pallet::call, require weight attribute i.e. `#[pallet::weight]`"
-					} else {
-						"Invalid pallet::call, to many weight attribute given"
-					};
-					return Err(syn::Error::new(method.sig.span(), msg));
-				}
-				let weight = call_var_attrs.pop().unwrap().weight;
-
-				let mut args = vec![];
-				for arg in method.sig.inputs.iter_mut().skip(1) {
-					let arg = if let syn::FnArg::Typed(arg) = arg {
-						arg
-					} else {
-						unreachable!("Only first argument can be receiver");
-					};
-
-					let arg_attrs: Vec<ArgAttrIsCompact> = take_item_attrs(&mut arg.attrs)?;
-
-					if arg_attrs.len() > 1 {
-						let msg = "Invalid pallet::call, argument has too many attributes";
-						return Err(syn::Error::new(arg.span(), msg));
-					}
-
-					let arg_ident = if let syn::Pat::Ident(pat) = &*arg.pat {
-						pat.ident.clone()
-					} else {
-						let msg = "Invalid pallet::call, argumen must be ident";
-						return Err(syn::Error::new(arg.pat.span(), msg));
-					};
-
-					args.push((!arg_attrs.is_empty(), arg_ident, arg.ty.clone()));
+			let index = call_var_attrs.iter()
+				.find_map(|attr| match attr {
+					FunctionAttr::CallIndex(i) => Some(*i),
+					_ => None,
+				});
+
+			let mut args = vec![];
+			let mut arg_errors: Vec<syn::Error> = vec![];
+			for arg in method.sig.inputs.iter_mut().skip(1) {
+				let arg = if let syn::FnArg::Typed(arg) = arg {
+					arg
+				} else {
+					unreachable!("Only first argument can be receiver");
+				};
+
+				let arg_attrs: Vec<ArgAttrIsCompact> = match take_item_attrs(&mut arg.attrs) {
+					Ok(attrs) => attrs,
+					Err(e) => {
+						arg_errors.push(e);
+						continue;
+					},
+				};
+
+				if arg_attrs.len() > 1 {
+					let msg = "Invalid pallet::call, argument has too many attributes";
+					arg_errors.push(syn::Error::new(arg.span(), msg));
+					continue;
 				}
 
-				let docs = get_doc_literals(&method.attrs);
+				let arg_ident = if let syn::Pat::Ident(pat) = &*arg.pat {
+					pat.ident.clone()
+				} else {
+					let msg = "Invalid pallet::call, argumen must be ident";
+					arg_errors.push(syn::Error::new(arg.pat.span(), msg));
+					continue;
+				};
 
-				methods.push(CallVariantDef {
-					fn_: method.sig.ident.clone(),
-					weight,
-					args,
-					docs,
-				});
-			} else {
-				let msg = "Invalid pallet::call, only method accepted";
-				return Err(syn::Error::new(impl_item.span(), msg));
+				args.push((!arg_attrs.is_empty(), arg_ident, arg.ty.clone()));
+			}
+			if !arg_errors.is_empty() {
+				errors.extend(arg_errors);
+				continue;
+			}
+
+			let docs = DocInfo::from_literals(&get_doc_literals(&method.attrs));
+
+			methods.push(CallVariantDef {
+				fn_: method.sig.ident.clone(),
+				weight,
+				index,
+				args,
+				docs,
+			});
+		}
+
+		let mut used_indices = HashMap::new();
+		for (position, method) in methods.iter().enumerate() {
+			let final_index = method.index.unwrap_or(position as u8);
+			if let Some(used_by) = used_indices.insert(final_index, &method.fn_) {
+				let msg = format!(
+					"Invalid pallet::call, call indices must be unique, `{}` and `{}` \
+					both resolve to call index {}",
+					used_by, method.fn_, final_index,
+				);
+				errors.push(syn::Error::new(method.fn_.span(), msg));
 			}
 		}
 
+		if let Some(error) = super::combine_errors(errors) {
+			return Err(error);
+		}
+
 		Ok(Self {
 			call,
 			instances,
@@ -172,3 +267,70 @@ impl CallDef {
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(item: syn::Item) -> syn::Result<CallDef> {
+		CallDef::try_from(item)
+	}
+
+	#[test]
+	fn explicit_index_colliding_with_implicit_index_is_rejected() {
+		// `bar` has no `#[pallet::call_index]`, so it keeps its declaration-order index, `1`;
+		// `foo`, the third item (position `2`), explicitly claims that same index, `1`.
+		let item: syn::Item = syn::parse_quote! {
+			impl<T: Config> Call for Module<T> {
+				#[pallet::weight = 0]
+				fn zero(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+
+				#[pallet::weight = 0]
+				fn bar(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+
+				#[pallet::weight = 0]
+				#[pallet::call_index(1)]
+				fn foo(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+			}
+		};
+
+		let err = parse(item).expect_err("explicit/implicit call index collision must error");
+		assert!(err.to_string().contains("call indices must be unique"));
+	}
+
+	#[test]
+	fn duplicate_explicit_indices_are_rejected() {
+		let item: syn::Item = syn::parse_quote! {
+			impl<T: Config> Call for Module<T> {
+				#[pallet::weight = 0]
+				#[pallet::call_index(3)]
+				fn foo(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+
+				#[pallet::weight = 0]
+				#[pallet::call_index(3)]
+				fn bar(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+			}
+		};
+
+		let err = parse(item).expect_err("duplicate explicit call indices must error");
+		assert!(err.to_string().contains("call indices must be unique"));
+	}
+
+	#[test]
+	fn distinct_explicit_and_implicit_indices_are_accepted() {
+		let item: syn::Item = syn::parse_quote! {
+			impl<T: Config> Call for Module<T> {
+				#[pallet::weight = 0]
+				#[pallet::call_index(5)]
+				fn foo(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+
+				#[pallet::weight = 0]
+				fn bar(origin: OriginFor<T>) -> DispatchResultWithPostInfo { Ok(().into()) }
+			}
+		};
+
+		let def = parse(item).expect("non-colliding call indices must parse");
+		assert_eq!(def.methods[0].index, Some(5));
+		assert_eq!(def.methods[1].index, None);
+	}
+}
+
@@ -0,0 +1,111 @@
+/// A doc comment split into a short summary and the remaining long-form description, so that
+/// generated metadata (and any UI built on top of it) can show a one-line summary without
+/// rendering the whole doc block.
+pub struct DocInfo {
+	/// Everything up to the first blank `///` line, soft-wrapped lines joined into one string.
+	pub summary: String,
+	/// Everything after the first blank `///` line, paragraphs joined the same way.
+	pub long: String,
+}
+
+impl DocInfo {
+	/// Build a `DocInfo` from the doc literals collected off an item's attributes, as returned
+	/// by `get_doc_literals`.
+	pub fn from_literals(docs: &[syn::Lit]) -> Self {
+		let raw_lines: Vec<String> = docs.iter()
+			.filter_map(|doc| if let syn::Lit::Str(s) = doc { Some(s.value()) } else { None })
+			.map(|line| {
+				// `///` doc lines are turned into `#[doc = " ..."]` by the compiler, with a
+				// single leading space when the author wrote one; strip only that one before
+				// computing the common indentation below, so authored indentation (a nested
+				// list, a code block) isn't thrown off by it.
+				line.strip_prefix(' ').map(str::to_string).unwrap_or(line)
+			})
+			.collect();
+
+		let common_indent = raw_lines.iter()
+			.filter(|line| !line.trim().is_empty())
+			.map(|line| line.len() - line.trim_start().len())
+			.min()
+			.unwrap_or(0);
+
+		let lines: Vec<String> = raw_lines.iter()
+			.map(|line| if line.trim().is_empty() {
+				String::new()
+			} else {
+				line.chars().skip(common_indent).collect()
+			})
+			.collect();
+
+		let blank_at = lines.iter().position(|line| line.trim().is_empty());
+		let (summary_lines, long_lines) = match blank_at {
+			Some(pos) => (&lines[..pos], &lines[pos + 1..]),
+			None => (&lines[..], &[][..]),
+		};
+
+		DocInfo {
+			summary: join_paragraph(summary_lines),
+			long: join_paragraphs(long_lines),
+		}
+	}
+}
+
+/// Join the soft-wrapped lines of a single paragraph into one string. Lines are already
+/// dedented to the doc comment's common indentation by `from_literals`.
+fn join_paragraph(lines: &[String]) -> String {
+	lines.iter().map(|line| line.trim()).collect::<Vec<_>>().join(" ").trim().to_string()
+}
+
+/// Join the remaining lines back into paragraphs separated by a blank line, soft-wrapping each
+/// paragraph's lines the same way `join_paragraph` does.
+fn join_paragraphs(lines: &[String]) -> String {
+	lines.split(|line| line.trim().is_empty())
+		.map(join_paragraph)
+		.collect::<Vec<_>>()
+		.join("\n\n")
+		.trim()
+		.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn doc_lits(lines: &[&str]) -> Vec<syn::Lit> {
+		lines.iter()
+			.map(|line| syn::Lit::Str(syn::LitStr::new(line, proc_macro2::Span::call_site())))
+			.collect()
+	}
+
+	#[test]
+	fn splits_summary_and_long_description() {
+		let docs = doc_lits(&[
+			" Short summary.",
+			"",
+			" First long paragraph",
+			" continued on a second line.",
+			"",
+			" Second long paragraph.",
+		]);
+
+		let info = DocInfo::from_literals(&docs);
+
+		assert_eq!(info.summary, "Short summary.");
+		assert_eq!(
+			info.long,
+			"First long paragraph continued on a second line.\n\nSecond long paragraph.",
+		);
+	}
+
+	#[test]
+	fn strips_common_leading_whitespace_not_just_one_space() {
+		let docs = doc_lits(&[
+			"   Indented summary line one",
+			"   indented summary line two.",
+		]);
+
+		let info = DocInfo::from_literals(&docs);
+
+		assert_eq!(info.summary, "Indented summary line one indented summary line two.");
+	}
+}
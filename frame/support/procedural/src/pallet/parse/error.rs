@@ -1,19 +1,31 @@
 use super::helper;
+use super::docs::DocInfo;
 use syn::spanned::Spanned;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 /// List of additional token to be used for parsing.
 mod keyword {
 	syn::custom_keyword!(Error);
 }
 
-/// This checks error declaration as a enum declaration with only variants without fields nor
-/// discriminant.
+/// A field carried by an error variant, as in `BalanceTooLow { required: Balance }` or
+/// `BalanceTooLow(Balance)`.
+pub struct FieldType {
+	/// The field ident, `None` for a tuple variant field.
+	pub ident: Option<syn::Ident>,
+	/// The field type. `syn` can't check trait bounds itself, so this isn't rejected here if it
+	/// doesn't implement `Encode`, `Decode` and `TypeInfo`; instead, `ErrorDef::bound_assertions`
+	/// emits a generated assertion per field type that expansion splices in next to the `Error`
+	/// enum, turning a missing bound into a compile error pointing at this field.
+	pub ty: syn::Type,
+}
+
+/// This checks error declaration as a enum declaration with variants without discriminant.
 pub struct ErrorDef {
 	/// The index of error item in pallet module.
 	pub index: usize,
-	/// Variants ident and doc literals (ordered as declaration order)
-	pub variants: Vec<(syn::Ident, Vec<syn::Lit>)>,
+	/// Variants ident, fields and doc info (ordered as declaration order)
+	pub variants: Vec<(syn::Ident, Vec<FieldType>, DocInfo)>,
 	/// A set of usage of instance, must be check for consistency with trait.
 	pub instances: Vec<helper::InstanceUsage>,
 	/// The keyword error used (contains span).
@@ -42,22 +54,37 @@ impl ErrorDef {
 
 		let error = syn::parse2::<keyword::Error>(item.ident.to_token_stream())?;
 
-		let variants = item.variants.iter()
-			.map(|variant| {
-				if !matches!(variant.fields, syn::Fields::Unit) {
-					let msg = "Invalid pallet::error, unexpected fields, must be `Unit`";
-					return Err(syn::Error::new(variant.fields.span(), msg));
-				}
-				if variant.discriminant.is_some() {
-					let msg = "Invalid pallet::error, unexpected discriminant, discriminant \
-						are not supported";
-					let span = variant.discriminant.as_ref().unwrap().0.span();
-					return Err(syn::Error::new(span, msg));
-				}
+		let mut errors = vec![];
+		let mut variants = vec![];
+		for variant in item.variants.iter() {
+			if variant.discriminant.is_some() {
+				let msg = "Invalid pallet::error, unexpected discriminant, discriminant \
+					are not supported";
+				let span = variant.discriminant.as_ref().unwrap().0.span();
+				errors.push(syn::Error::new(span, msg));
+				continue;
+			}
+
+			let fields = match &variant.fields {
+				syn::Fields::Unit => vec![],
+				syn::Fields::Unnamed(fields) => fields.unnamed.iter()
+					.map(|field| FieldType { ident: None, ty: field.ty.clone() })
+					.collect(),
+				syn::Fields::Named(fields) => fields.named.iter()
+					.map(|field| FieldType {
+						ident: Some(field.ident.clone().expect("Named field has an ident; qed")),
+						ty: field.ty.clone(),
+					})
+					.collect(),
+			};
+
+			let docs = DocInfo::from_literals(&helper::get_doc_literals(&variant.attrs));
+			variants.push((variant.ident.clone(), fields, docs));
+		}
 
-				Ok((variant.ident.clone(), helper::get_doc_literals(&variant.attrs)))
-			})
-			.collect::<Result<_, _>>()?;
+		if let Some(error) = super::combine_errors(errors) {
+			return Err(error);
+		}
 
 		Ok(ErrorDef {
 			index,
@@ -66,4 +93,26 @@ impl ErrorDef {
 			error,
 		})
 	}
+
+	/// Tokens asserting that every carried field type implements `Encode`, `Decode` and
+	/// `TypeInfo`. Expansion must splice these in alongside the generated `Error` enum so that a
+	/// field type missing one of these bounds fails to compile with a clear message, instead of
+	/// silently producing an enum that can't be SCALE-encoded or have its metadata generated.
+	pub fn bound_assertions(&self) -> proc_macro2::TokenStream {
+		let asserts = self.variants.iter()
+			.flat_map(|(_, fields, _)| fields.iter())
+			.map(|field| {
+				let ty = &field.ty;
+				quote! {
+					const _: fn() = || {
+						fn assert_error_field_bounds<
+							T: codec::Encode + codec::Decode + scale_info::TypeInfo
+						>() {}
+						assert_error_field_bounds::<#ty>();
+					};
+				}
+			});
+
+		quote!( #( #asserts )* )
+	}
 }
@@ -0,0 +1,106 @@
+//! Stable, documented entry points for external tooling (build scripts, pallet scaffolding,
+//! runtime-generation tools that programmatically append dispatchables via `syn`) that wants to
+//! enumerate a pallet's calls and errors without re-parsing the token stream or duplicating the
+//! validation logic already implemented in `CallDef::try_from`/`ErrorDef::try_from`.
+
+use super::call::CallDef;
+use super::error::ErrorDef;
+use quote::ToTokens;
+
+/// A single dispatchable argument, flattened to strings so it travels well across a process
+/// boundary (e.g. to a build script invoked as a subprocess).
+pub struct CallArgInfo {
+	/// Argument name.
+	pub name: String,
+	/// Argument type, rendered as source.
+	pub ty: String,
+	/// Whether the argument is annotated `#[pallet::compact]`.
+	pub is_compact: bool,
+}
+
+/// Salient, string-serializable view of a single dispatchable.
+pub struct CallInfo {
+	/// Function name.
+	pub name: String,
+	/// Explicit `#[pallet::call_index(n)]`, if any; `None` means the declaration-order index.
+	pub index: Option<u8>,
+	/// The dispatchable's arguments, in declaration order (origin excluded).
+	pub args: Vec<CallArgInfo>,
+	/// Weight formula, rendered as source.
+	pub weight: String,
+	/// Doc summary (first paragraph).
+	pub doc_summary: String,
+	/// Doc long description (remaining paragraphs).
+	pub doc_long: String,
+}
+
+/// A single error variant field, flattened to strings the same way [`CallArgInfo`] is.
+pub struct ErrorFieldInfo {
+	/// Field name, `None` for a tuple variant field.
+	pub name: Option<String>,
+	/// Field type, rendered as source.
+	pub ty: String,
+}
+
+/// Salient, string-serializable view of a single error variant.
+pub struct ErrorVariantInfo {
+	/// Variant name.
+	pub name: String,
+	/// Carried fields, in declaration order.
+	pub fields: Vec<ErrorFieldInfo>,
+	/// Doc summary (first paragraph).
+	pub doc_summary: String,
+	/// Doc long description (remaining paragraphs).
+	pub doc_long: String,
+}
+
+/// Parse `impl<T: Config> Call for Module<T> { .. }` and return both the full [`CallDef`] (used
+/// by macro expansion) and a serializable summary of its dispatchables (for external tooling).
+pub fn parse_call(item: syn::ItemImpl) -> syn::Result<(CallDef, Vec<CallInfo>)> {
+	let def = CallDef::try_from(syn::Item::Impl(item))?;
+
+	let info = def.methods.iter()
+		.map(|method| CallInfo {
+			name: method.fn_.to_string(),
+			index: method.index,
+			args: method.args.iter()
+				.map(|(is_compact, ident, ty)| CallArgInfo {
+					name: ident.to_string(),
+					ty: ty.to_token_stream().to_string(),
+					is_compact: *is_compact,
+				})
+				.collect(),
+			weight: method.weight.to_token_stream().to_string(),
+			doc_summary: method.docs.summary.clone(),
+			doc_long: method.docs.long.clone(),
+		})
+		.collect();
+
+	Ok((def, info))
+}
+
+/// Parse a `#[pallet::error]` enum and return both the full [`ErrorDef`] (used by macro
+/// expansion) and a serializable summary of its variants (for external tooling).
+pub fn parse_error(
+	index: usize,
+	item: syn::ItemEnum,
+) -> syn::Result<(ErrorDef, Vec<ErrorVariantInfo>)> {
+	let mut item = syn::Item::Enum(item);
+	let def = ErrorDef::try_from(index, &mut item)?;
+
+	let info = def.variants.iter()
+		.map(|(ident, fields, docs)| ErrorVariantInfo {
+			name: ident.to_string(),
+			fields: fields.iter()
+				.map(|field| ErrorFieldInfo {
+					name: field.ident.as_ref().map(|ident| ident.to_string()),
+					ty: field.ty.to_token_stream().to_string(),
+				})
+				.collect(),
+			doc_summary: docs.summary.clone(),
+			doc_long: docs.long.clone(),
+		})
+		.collect();
+
+	Ok((def, info))
+}
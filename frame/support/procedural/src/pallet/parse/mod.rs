@@ -0,0 +1,14 @@
+/// Fold a list of parse errors collected while validating a `pallet::call`/`pallet::error` item
+/// into a single [`syn::Error`], using the first as the accumulator and folding the rest into it
+/// via `syn::Error::combine`. Returns `None` if `errors` is empty.
+///
+/// Centralizes the pattern shared by `CallDef::try_from` and `ErrorDef::try_from`, which both
+/// need to report every parse error found in a single compile instead of just the first.
+pub(crate) fn combine_errors(errors: Vec<syn::Error>) -> Option<syn::Error> {
+	let mut errors = errors.into_iter();
+	let first = errors.next()?;
+	Some(errors.fold(first, |mut acc, error| {
+		acc.combine(error);
+		acc
+	}))
+}